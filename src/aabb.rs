@@ -0,0 +1,65 @@
+use vek::Vec3;
+
+use crate::Ray;
+
+/// An axis-aligned bounding box, used by the BVH to skip whole subtrees of
+/// primitives a ray can't possibly hit.
+#[derive(Clone, Copy)]
+pub struct Aabb {
+    pub min: Vec3<f64>,
+    pub max: Vec3<f64>,
+}
+
+impl Aabb {
+    pub fn new(min: Vec3<f64>, max: Vec3<f64>) -> Self {
+        Self { min, max }
+    }
+
+    /// The smallest box containing both `a` and `b`.
+    pub fn surrounding(a: &Aabb, b: &Aabb) -> Aabb {
+        let min = Vec3::new(
+            a.min.x.min(b.min.x),
+            a.min.y.min(b.min.y),
+            a.min.z.min(b.min.z),
+        );
+        let max = Vec3::new(
+            a.max.x.max(b.max.x),
+            a.max.y.max(b.max.y),
+            a.max.z.max(b.max.z),
+        );
+        Aabb::new(min, max)
+    }
+
+    /// Slab test: for each axis, clip `[t_min, t_max]` down to the interval
+    /// during which the ray is within that axis's slab, rejecting as soon as
+    /// the interval becomes empty.
+    pub fn hit(&self, ray: &Ray, mut t_min: f64, mut t_max: f64) -> bool {
+        for axis in 0..3 {
+            let origin = component(ray.origin, axis);
+            let direction = component(ray.direction, axis);
+            let min = component(self.min, axis);
+            let max = component(self.max, axis);
+
+            let inv_d = 1. / direction;
+            let (mut t0, mut t1) = ((min - origin) * inv_d, (max - origin) * inv_d);
+            if inv_d < 0. {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_max <= t_min {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+pub fn component(v: Vec3<f64>, axis: usize) -> f64 {
+    match axis {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
@@ -0,0 +1,85 @@
+use crate::aabb::{component, Aabb};
+use crate::hittable::{HitRecord, Hittable};
+use crate::Ray;
+
+/// A binary BVH node. Traversal checks `bbox` once and only recurses into
+/// children whose own box the ray actually hits, turning the per-ray cost
+/// from O(objects) into roughly O(log objects).
+pub struct BvhNode {
+    left: Box<dyn Hittable>,
+    right: Box<dyn Hittable>,
+    bbox: Aabb,
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        if !self.bbox.hit(ray, t_min, t_max) {
+            return None;
+        }
+
+        let left_hit = self.left.hit(ray, t_min, t_max);
+        let closest = left_hit.as_ref().map_or(t_max, |rec| rec.t);
+        let right_hit = self.right.hit(ray, t_min, closest);
+
+        right_hit.or(left_hit)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.bbox)
+    }
+}
+
+/// Recursively splits `objects` at the median along whichever axis has the
+/// largest extent (round-robin would work too; this adapts better to
+/// unevenly shaped scenes) until each leaf holds a single primitive.
+pub fn build(mut objects: Vec<Box<dyn Hittable>>) -> Box<dyn Hittable> {
+    assert!(!objects.is_empty(), "cannot build a BVH with no objects");
+
+    if objects.len() == 1 {
+        return objects.pop().unwrap();
+    }
+
+    if objects.len() == 2 {
+        let b = objects.pop().unwrap();
+        let a = objects.pop().unwrap();
+        let bbox = Aabb::surrounding(&bounding_box(a.as_ref()), &bounding_box(b.as_ref()));
+        return Box::new(BvhNode {
+            left: a,
+            right: b,
+            bbox,
+        });
+    }
+
+    let bounds = objects
+        .iter()
+        .map(|o| bounding_box(o.as_ref()))
+        .reduce(|a, b| Aabb::surrounding(&a, &b))
+        .unwrap();
+    let extent = bounds.max - bounds.min;
+    let axis = if extent.x >= extent.y && extent.x >= extent.z {
+        0
+    } else if extent.y >= extent.z {
+        1
+    } else {
+        2
+    };
+
+    objects.sort_by(|a, b| {
+        let ca = component(bounding_box(a.as_ref()).min, axis);
+        let cb = component(bounding_box(b.as_ref()).min, axis);
+        ca.partial_cmp(&cb).unwrap()
+    });
+
+    let right_objects = objects.split_off(objects.len() / 2);
+    let left = build(objects);
+    let right = build(right_objects);
+    let bbox = Aabb::surrounding(&bounding_box(left.as_ref()), &bounding_box(right.as_ref()));
+
+    Box::new(BvhNode { left, right, bbox })
+}
+
+fn bounding_box(object: &dyn Hittable) -> Aabb {
+    object
+        .bounding_box()
+        .expect("every primitive in a BVH must be bounded")
+}
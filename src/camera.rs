@@ -0,0 +1,89 @@
+use rand::Rng;
+use vek::Vec3;
+
+use crate::Ray;
+
+/// A positionable pinhole (or, with `aperture > 0`, thin-lens) camera that
+/// emits primary rays through a virtual image plane derived from
+/// `lookfrom`/`lookat`/`vup`.
+pub struct Camera {
+    origin: Vec3<f64>,
+    lower_left: Vec3<f64>,
+    horizontal: Vec3<f64>,
+    vertical: Vec3<f64>,
+    u: Vec3<f64>,
+    v: Vec3<f64>,
+    lens_radius: f64,
+    time0: f64,
+    time1: f64,
+}
+
+impl Camera {
+    /// `vfov` is the vertical field of view in radians, `aspect` is width / height.
+    /// `aperture` is the lens diameter and `focus_dist` the distance to the
+    /// plane that's in perfect focus; pass `aperture: 0.` for a pinhole camera.
+    /// `time0`/`time1` is the shutter interval primary rays are timestamped
+    /// within, for motion blur; pass `time0 == time1` for an instantaneous shutter.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        lookfrom: Vec3<f64>,
+        lookat: Vec3<f64>,
+        vup: Vec3<f64>,
+        vfov: f64,
+        aspect: f64,
+        aperture: f64,
+        focus_dist: f64,
+        time0: f64,
+        time1: f64,
+    ) -> Self {
+        let half_height = (vfov / 2.).tan();
+        let half_width = aspect * half_height;
+
+        let w = (lookfrom - lookat).normalized();
+        let u = vup.cross(w).normalized();
+        let v = w.cross(u);
+
+        let lower_left =
+            lookfrom - u * (half_width * focus_dist) - v * (half_height * focus_dist) - w * focus_dist;
+        let horizontal = u * (2. * half_width * focus_dist);
+        let vertical = v * (2. * half_height * focus_dist);
+
+        Self {
+            origin: lookfrom,
+            lower_left,
+            horizontal,
+            vertical,
+            u,
+            v,
+            lens_radius: aperture / 2.,
+            time0,
+            time1,
+        }
+    }
+
+    /// `s` and `t` are screen-space fractions in `[0, 1]`, with `(0, 0)` at the
+    /// bottom-left of the image plane. Rays are offset across the lens disk
+    /// when `lens_radius > 0`, producing depth-of-field blur, and timestamped
+    /// at a random instant within the shutter interval for motion blur.
+    pub fn get_ray(&self, rng: &mut impl Rng, s: f64, t: f64) -> Ray {
+        let rd = random_in_unit_disk(rng) * self.lens_radius;
+        let offset = self.u * rd.x + self.v * rd.y;
+        let origin = self.origin + offset;
+        let direction = self.lower_left + self.horizontal * s + self.vertical * t - origin;
+        let time = if self.time1 > self.time0 {
+            rng.gen_range(self.time0..self.time1)
+        } else {
+            self.time0
+        };
+        Ray::new(origin, direction.normalized(), time)
+    }
+}
+
+fn random_in_unit_disk(rng: &mut impl Rng) -> Vec3<f64> {
+    loop {
+        let p = Vec3::new(rng.gen_range(-1. ..1.), rng.gen_range(-1. ..1.), 0.);
+        if p.dot(p) < 1. {
+            return p;
+        }
+    }
+}
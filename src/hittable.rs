@@ -0,0 +1,50 @@
+use vek::Vec3;
+
+use crate::aabb::Aabb;
+use crate::material::Material;
+use crate::Ray;
+
+/// What a ray hit: the parametric distance, the world-space point and
+/// normal, the material at that point, and which side of the surface the
+/// ray approached from.
+///
+/// `normal` always opposes the incoming ray (`front_face` records whether
+/// that required flipping the primitive's outward-facing normal), so
+/// downstream shading code never has to re-derive which side it's on.
+pub struct HitRecord {
+    pub t: f64,
+    pub point: Vec3<f64>,
+    pub normal: Vec3<f64>,
+    pub material: Material,
+    pub front_face: bool,
+}
+
+impl HitRecord {
+    pub fn new(ray: &Ray, t: f64, point: Vec3<f64>, outward_normal: Vec3<f64>, material: Material) -> Self {
+        let front_face = ray.direction.dot(outward_normal) < 0.;
+        let normal = if front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+
+        Self {
+            t,
+            point,
+            normal,
+            material,
+            front_face,
+        }
+    }
+}
+
+/// Anything a `Ray` can intersect: spheres, planes, triangles, BVH nodes, and
+/// the `Scene` itself (which just forwards to its top-level object).
+pub trait Hittable: Sync {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord>;
+
+    /// A box enclosing every point the primitive can be hit at, used by the
+    /// BVH to cull whole subtrees. `None` for primitives with no finite
+    /// extent (there are none yet, but the BVH builder treats it as fatal).
+    fn bounding_box(&self) -> Option<Aabb>;
+}
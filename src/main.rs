@@ -1,15 +1,35 @@
 use std::fs::*;
 use std::io::{BufWriter, prelude::*};
 
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+use rayon::prelude::*;
 use vek::{Rgb, Vec3};
 
-struct Light {
+mod aabb;
+mod bvh;
+mod camera;
+mod hittable;
+mod material;
+mod obj;
+mod optics;
+mod pathtracer;
+mod primitives;
+mod scene;
+mod scene_file;
+
+use camera::Camera;
+use hittable::Hittable;
+use optics::{offset_point, reflect, refract};
+use scene::Scene;
+
+pub(crate) struct Light {
     position: Vec3<f64>,
     intensity: f64,
 }
 
 impl Light {
-    fn new(position: Vec3<f64>, intensity: f64) -> Self {
+    pub(crate) fn new(position: Vec3<f64>, intensity: f64) -> Self {
         Self {
             position,
             intensity,
@@ -21,198 +41,39 @@ impl Light {
 struct Ray {
     origin: Vec3<f64>,
     direction: Vec3<f64>,
+    time: f64,
 }
 
 impl Ray {
-    fn new(origin: Vec3<f64>, direction: Vec3<f64>) -> Self {
-        Self { origin, direction }
-    }
-}
-
-#[derive(Clone, Copy)]
-struct Material {
-    refractive_index: f64,
-    albedo: [f64; 4],
-    diffuse_color: Rgb<f64>,
-    specular_exponent: f64,
-}
-
-impl Material {
-    fn new(
-        refractive_index: f64,
-        albedo: [f64; 4],
-        color: Rgb<f64>,
-        specular_exponent: f64,
-    ) -> Self {
-        Self {
-            refractive_index,
-            albedo,
-            diffuse_color: color,
-            specular_exponent,
-        }
-    }
-}
-
-impl Default for Material {
-    fn default() -> Self {
-        Self {
-            refractive_index: 1.,
-            albedo: [1., 0., 0., 0.],
-            diffuse_color: Rgb::black(),
-            specular_exponent: 0.,
-        }
-    }
-}
-
-trait Intersect {
-    type Output;
-
-    fn intersect(&self, ray: &Ray) -> Option<Self::Output>;
-}
-
-#[derive(Clone, Copy)]
-struct Sphere {
-    center: Vec3<f64>,
-    radius: f64,
-    material: Material,
-}
-
-impl Sphere {
-    fn new(center: Vec3<f64>, radius: f64, material: Material) -> Self {
+    fn new(origin: Vec3<f64>, direction: Vec3<f64>, time: f64) -> Self {
         Self {
-            center,
-            radius,
-            material,
+            origin,
+            direction,
+            time,
         }
     }
 }
-impl Intersect for Sphere {
-    type Output = f64;
-
-    fn intersect(&self, ray: &Ray) -> Option<Self::Output> {
-        let v_l = self.center - ray.origin;
-        let tca = v_l.dot(ray.direction);
-        let d2 = v_l.dot(v_l) - tca * tca;
-        let radius2 = self.radius * self.radius;
-
-        if d2 > radius2 {
-            None
-        } else {
-            let thc = (radius2 - d2).sqrt();
-            let t0 = tca - thc;
-            let t1 = tca + thc;
-
-            if t1 < 0. {
-                None
-            } else {
-                Some(if t0 < 0. { t1 } else { t0 })
-            }
-        }
-    }
-}
-
-fn reflect(v_in: &Vec3<f64>, v_normal: &Vec3<f64>) -> Vec3<f64> {
-    v_in - 2. * *v_normal * v_in.dot(*v_normal)
-}
-
-fn refract(v_in: &Vec3<f64>, v_normal: &Vec3<f64>, refractive_index: f64) -> Vec3<f64> {
-    let mut cosi = -v_in.dot(*v_normal).clamp(-1., 1.);
-    let mut etai = 1.0;
-    let mut etat = refractive_index;
-    let mut n = *v_normal;
-    if cosi < 0. {
-        cosi = -cosi;
-        std::mem::swap(&mut etai, &mut etat);
-        n = -n;
-    }
-    let eta = etai / etat;
-    let k = 1.0 - eta * eta * (1.0 - cosi * cosi);
-    if k < 0. {
-        Vec3::zero()
-    } else {
-        v_in * eta + n * (eta * cosi - k.sqrt())
-    }
-}
 
-struct Scene {
-    spheres: Vec<Sphere>,
+/// Color of the emissive sky: the Whitted caster's ray-miss background, and
+/// the only light source the path tracer needs to illuminate a scene.
+pub(crate) fn sky_color() -> Rgb<f64> {
+    Rgb::new(0.2, 0.7, 0.8)
 }
 
-impl Scene {
-    fn new(spheres: Vec<Sphere>) -> Self {
-        Self { spheres }
-    }
-}
-impl Intersect for Scene {
-    type Output = (Vec3<f64>, Vec3<f64>, Material);
-
-    fn intersect(&self, ray: &Ray) -> Option<Self::Output> {
-        let mut hit = Vec3::default();
-        let mut v_normal = Vec3::default();
-        let mut material = Material::default();
-        let mut spheres_dist = f64::MAX;
-
-        for sphere in &self.spheres {
-            let dist_i = sphere.intersect(ray);
-
-            if let Some(dist_i) = dist_i {
-                if dist_i < spheres_dist {
-                    spheres_dist = dist_i;
-                    hit = ray.origin + ray.direction * dist_i;
-                    v_normal = (hit - sphere.center).normalized();
-                    material = sphere.material;
-                }
-            }
-        }
-
-        let mut checkerboard_dist = f64::MAX;
-        if ray.direction.y.abs() > 1e-3 {
-            let d = -(ray.origin.y + 4.) / ray.direction.y;
-            let pt = ray.origin + ray.direction * d;
-            if d > 0. && pt.x.abs() < 10. && pt.z < -10. && pt.z > -30. && d < spheres_dist {
-                checkerboard_dist = d;
-                hit = pt;
-                v_normal = Vec3::new(0., 1., 0.);
-                material.diffuse_color =
-                    if ((0.5 * hit.x + 1000.) as isize + (0.5 * hit.z) as isize) & 1 == 1 {
-                        Rgb::new(0.3, 0.3, 0.3)
-                    } else {
-                        Rgb::new(0.3, 0.2, 0.1)
-                    };
-            }
-        }
-
-        if spheres_dist.min(checkerboard_dist) < 1000. {
-            Some((hit, v_normal, material))
-        } else {
-            None
-        }
-    }
-}
-
-fn offset_point(point: &Vec3<f64>, normal: &Vec3<f64>, dot_product: f64) -> Vec3<f64> {
-    if dot_product < 0.0 {
-        *point - *normal * 1e-3
-    } else {
-        *point + *normal * 1e-3
-    }
-}
-
-fn cast_ray(ray: &Ray, spheres: &[Sphere], lights: &[Light], depth: usize) -> (Rgb<f64>, usize) {
-    let scene = Scene::new(spheres.to_vec());
-
-    if depth > 4 {
-        (Rgb::new(0.2, 0.7, 0.8), depth)
-    } else if let Some((point, v_normal, material)) = scene.intersect(ray) {
+fn cast_ray(ray: &Ray, scene: &Scene, lights: &[Light], depth: usize, max_depth: usize) -> (Rgb<f64>, usize) {
+    if depth > max_depth {
+        (sky_color(), depth)
+    } else if let Some(rec) = scene.hit(ray, 1e-3, f64::MAX) {
+        let (point, v_normal, material) = (rec.point, rec.normal, rec.material);
         let reflect_dir = reflect(&ray.direction, &v_normal).normalized();
         let refract_dir =
-            refract(&ray.direction, &v_normal, material.refractive_index).normalized();
+            refract(&ray.direction, &v_normal, rec.front_face, material.refractive_index).normalized();
         let reflect_orig = offset_point(&point, &v_normal, reflect_dir.dot(v_normal));
         let refract_orig = offset_point(&point, &v_normal, refract_dir.dot(v_normal));
-        let reflect_ray = Ray::new(reflect_orig, reflect_dir);
-        let refract_ray = Ray::new(refract_orig, refract_dir);
-        let (reflect_color, _) = cast_ray(&reflect_ray, spheres, lights, depth + 1);
-        let (refract_color, _) = cast_ray(&refract_ray, spheres, lights, depth + 1);
+        let reflect_ray = Ray::new(reflect_orig, reflect_dir, ray.time);
+        let refract_ray = Ray::new(refract_orig, refract_dir, ray.time);
+        let (reflect_color, _) = cast_ray(&reflect_ray, scene, lights, depth + 1, max_depth);
+        let (refract_color, _) = cast_ray(&refract_ray, scene, lights, depth + 1, max_depth);
 
         let mut diffuse_light_intensity: f64 = 0.;
         let mut specular_light_intensity: f64 = 0.;
@@ -222,10 +83,11 @@ fn cast_ray(ray: &Ray, spheres: &[Sphere], lights: &[Light], depth: usize) -> (R
             let light_distance = v_light.magnitude();
 
             let shadow_orig = offset_point(&point, &v_normal, light_dir.dot(v_normal));
-            if let Some((shadow_pt, _, _)) = scene.intersect(&Ray::new(shadow_orig, light_dir)) {
-                if (shadow_pt - shadow_orig).magnitude() < light_distance {
-                    continue;
-                }
+            if scene
+                .hit(&Ray::new(shadow_orig, light_dir, ray.time), 1e-3, light_distance)
+                .is_some()
+            {
+                continue;
             }
 
             diffuse_light_intensity += light.intensity * light_dir.dot(v_normal).max(0.);
@@ -243,33 +105,59 @@ fn cast_ray(ray: &Ray, spheres: &[Sphere], lights: &[Light], depth: usize) -> (R
             depth,
         )
     } else {
-        (Rgb::new(0.2, 0.7, 0.8), depth)
+        (sky_color(), depth)
     }
 }
 
-fn render(spheres: &[Sphere], lights: &[Light]) {
-    const WIDTH: usize = 1024;
-    const HEIGHT: usize = 768;
-    const FOV: usize = std::f64::consts::FRAC_PI_2 as usize;
-    let mut framebuffer = vec![vec![Rgb::<f64>::zero(); WIDTH]; HEIGHT];
-
-    let aspect_ratio = WIDTH as f64 / HEIGHT as f64;
-    let scale = (FOV as f64 / 2.).tan();
-
-    for (j, row) in framebuffer.iter_mut().enumerate() {
-        for (i, pixel) in row.iter_mut().enumerate() {
-            let x = (2. * (i as f64 + 0.5) / WIDTH as f64 - 1.) * scale * aspect_ratio;
-            let y = -(2. * (j as f64 + 0.5) / HEIGHT as f64 - 1.) * scale;
-            let dir = Vec3::new(x, y, -1.).normalized();
-            let ray = Ray::new(Vec3::zero(), dir);
-            (*pixel, _) = cast_ray(&ray, spheres, lights, 0);
-        }
-    }
+const RNG_SEED: u64 = 42;
+
+/// Which integrator `render` drives: the explicit-light Whitted ray caster,
+/// or the emissive-material path tracer. Selected per scene by
+/// `scene_file::SceneDesc`'s `renderer` field.
+pub(crate) enum Renderer {
+    Whitted,
+    PathTracer,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render(
+    camera: &Camera,
+    scene: &Scene,
+    lights: &[Light],
+    width: usize,
+    height: usize,
+    max_depth: usize,
+    spp: usize,
+    renderer: Renderer,
+) {
+    let mut framebuffer = vec![vec![Rgb::<f64>::zero(); width]; height];
+
+    framebuffer
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(j, row)| {
+            let mut rng = Pcg64::seed_from_u64(RNG_SEED ^ j as u64);
+
+            for (i, pixel) in row.iter_mut().enumerate() {
+                let mut color = Rgb::<f64>::zero();
+                for _ in 0..spp {
+                    let s = (i as f64 + rng.gen::<f64>()) / width as f64;
+                    let t = 1. - (j as f64 + rng.gen::<f64>()) / height as f64;
+                    let ray = camera.get_ray(&mut rng, s, t);
+                    let sample = match renderer {
+                        Renderer::Whitted => cast_ray(&ray, scene, lights, 0, max_depth).0,
+                        Renderer::PathTracer => pathtracer::path_trace(&ray, scene, 0, max_depth, &mut rng),
+                    };
+                    color += sample;
+                }
+                *pixel = color / spp as f64;
+            }
+        });
 
     let file = File::create("./target/out.ppm").unwrap();
     let mut buffer = BufWriter::new(file);
     buffer
-        .write_fmt(format_args!("P6\n{} {}\n255\n", WIDTH, HEIGHT))
+        .write_fmt(format_args!("P6\n{} {}\n255\n", width, height))
         .unwrap();
 
     for row in framebuffer {
@@ -287,23 +175,20 @@ fn render(spheres: &[Sphere], lights: &[Light]) {
 }
 
 fn main() {
-    let ivory = Material::new(1.0, [0.6, 0.3, 0.1, 0.0], Rgb::new(0.4, 0.4, 0.3), 50.);
-    let glass = Material::new(1.5, [0.0, 0.5, 0.1, 0.8], Rgb::new(0.6, 0.7, 0.8), 125.);
-    let red_rubber = Material::new(1.0, [0.9, 0.1, 0.0, 0.0], Rgb::new(0.3, 0.1, 0.1), 10.);
-    let mirror = Material::new(1.0, [0.0, 10.0, 0.8, 0.0], Rgb::new(1.0, 1.0, 1.0), 1425.);
-
-    let spheres = vec![
-        Sphere::new(Vec3::new(-3., 0., -16.), 2., ivory),
-        Sphere::new(Vec3::new(-1., -1.5, -12.), 2., glass),
-        Sphere::new(Vec3::new(1.5, -0.5, -18.), 3., red_rubber),
-        Sphere::new(Vec3::new(7., 5., -18.), 4., mirror),
-    ];
-
-    let lights = vec![
-        Light::new(Vec3::new(-20., 20., 20.), 1.5),
-        Light::new(Vec3::new(30., 50., -25.), 1.8),
-        Light::new(Vec3::new(30., 20., 30.), 1.7),
-    ];
-
-    render(&spheres, &lights);
+    let path = std::env::args()
+        .nth(1)
+        .expect("usage: tinyraytracer <scene.json>");
+    let loaded = scene_file::load(&path).unwrap_or_else(|e| panic!("failed to load {path}: {e}"));
+
+    const SAMPLES_PER_PIXEL: usize = 16;
+    render(
+        &loaded.camera,
+        &loaded.scene,
+        &loaded.lights,
+        loaded.width,
+        loaded.height,
+        loaded.max_depth,
+        SAMPLES_PER_PIXEL,
+        loaded.renderer,
+    );
 }
@@ -0,0 +1,61 @@
+use vek::Rgb;
+
+/// How a surface scatters light in the path tracer. The Whitted ray caster
+/// ignores this and instead drives everything off `albedo`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MaterialKind {
+    Diffuse,
+    Glossy,
+    Mirror,
+}
+
+#[derive(Clone, Copy)]
+pub struct Material {
+    pub refractive_index: f64,
+    pub albedo: [f64; 4],
+    pub diffuse_color: Rgb<f64>,
+    pub specular_exponent: f64,
+    pub emission: Rgb<f64>,
+    pub kind: MaterialKind,
+}
+
+impl Material {
+    pub fn new(
+        refractive_index: f64,
+        albedo: [f64; 4],
+        color: Rgb<f64>,
+        specular_exponent: f64,
+    ) -> Self {
+        Self {
+            refractive_index,
+            albedo,
+            diffuse_color: color,
+            specular_exponent,
+            emission: Rgb::black(),
+            kind: MaterialKind::Diffuse,
+        }
+    }
+
+    pub fn with_emission(mut self, emission: Rgb<f64>) -> Self {
+        self.emission = emission;
+        self
+    }
+
+    pub fn with_kind(mut self, kind: MaterialKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            refractive_index: 1.,
+            albedo: [1., 0., 0., 0.],
+            diffuse_color: Rgb::black(),
+            specular_exponent: 0.,
+            emission: Rgb::black(),
+            kind: MaterialKind::Diffuse,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::io;
+
+use vek::Vec3;
+
+use crate::material::Material;
+use crate::primitives::Triangle;
+
+/// Parses the `v`/`f` records of a Wavefront `.obj` file into a flat list of
+/// triangles sharing `material`. Normals, texture coordinates, and groups are
+/// ignored; faces with more than three vertices are fan-triangulated around
+/// their first vertex.
+pub fn load_obj(path: &str, material: Material) -> io::Result<Vec<Triangle>> {
+    let contents = fs::read_to_string(path)?;
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for line in contents.lines() {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let coords: Vec<f64> = tokens.filter_map(|t| t.parse().ok()).collect();
+                if coords.len() >= 3 {
+                    vertices.push(Vec3::new(coords[0], coords[1], coords[2]));
+                }
+            }
+            Some("f") => {
+                let mut indices = Vec::new();
+                for token in tokens {
+                    let raw = token.split('/').next().unwrap_or(token);
+                    let n: isize = raw
+                        .parse()
+                        .map_err(|_| invalid_data(format!("malformed face index {raw:?} in {path}")))?;
+
+                    let index = match n.cmp(&0) {
+                        std::cmp::Ordering::Greater => (n - 1) as usize,
+                        std::cmp::Ordering::Less => {
+                            let relative = vertices.len() as isize + n;
+                            if relative < 0 {
+                                return Err(invalid_data(format!(
+                                    "face index {n} in {path} refers before the start of the vertex list"
+                                )));
+                            }
+                            relative as usize
+                        }
+                        std::cmp::Ordering::Equal => {
+                            return Err(invalid_data(format!("face index 0 in {path} is not valid")));
+                        }
+                    };
+
+                    if index >= vertices.len() {
+                        return Err(invalid_data(format!(
+                            "face in {path} references vertex {} but only {} are defined",
+                            index + 1,
+                            vertices.len()
+                        )));
+                    }
+                    indices.push(index);
+                }
+
+                for i in 1..indices.len().saturating_sub(1) {
+                    triangles.push(Triangle::new(
+                        vertices[indices[0]],
+                        vertices[indices[i]],
+                        vertices[indices[i + 1]],
+                        material,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(triangles)
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,53 @@
+use rand::Rng;
+use vek::Vec3;
+
+pub fn offset_point(point: &Vec3<f64>, normal: &Vec3<f64>, dot_product: f64) -> Vec3<f64> {
+    if dot_product < 0.0 {
+        *point - *normal * 1e-3
+    } else {
+        *point + *normal * 1e-3
+    }
+}
+
+pub fn reflect(v_in: &Vec3<f64>, v_normal: &Vec3<f64>) -> Vec3<f64> {
+    v_in - 2. * *v_normal * v_in.dot(*v_normal)
+}
+
+/// `v_normal` must already oppose `v_in` (as produced by `HitRecord`), and
+/// `front_face` says whether the ray is entering (`true`) or leaving
+/// (`false`) the material, so the caller no longer needs to detect that by
+/// inspecting the sign of `v_in · v_normal` itself.
+pub fn refract(v_in: &Vec3<f64>, v_normal: &Vec3<f64>, front_face: bool, refractive_index: f64) -> Vec3<f64> {
+    let (etai, etat) = if front_face {
+        (1.0, refractive_index)
+    } else {
+        (refractive_index, 1.0)
+    };
+    let cosi = (-v_in.dot(*v_normal)).clamp(-1., 1.);
+    let eta = etai / etat;
+    let k = 1.0 - eta * eta * (1.0 - cosi * cosi);
+    if k < 0. {
+        Vec3::zero()
+    } else {
+        v_in * eta + *v_normal * (eta * cosi - k.sqrt())
+    }
+}
+
+/// Uniformly distributed point inside the unit sphere, via rejection sampling.
+pub fn random_in_unit_sphere(rng: &mut impl Rng) -> Vec3<f64> {
+    loop {
+        let p = Vec3::new(
+            rng.gen_range(-1. ..1.),
+            rng.gen_range(-1. ..1.),
+            rng.gen_range(-1. ..1.),
+        );
+        if p.dot(p) < 1. {
+            return p;
+        }
+    }
+}
+
+/// A unit vector uniformly distributed over the sphere's surface.
+pub fn random_unit_vector(rng: &mut impl Rng) -> Vec3<f64> {
+    random_in_unit_sphere(rng).normalized()
+}
@@ -0,0 +1,50 @@
+use rand::Rng;
+use vek::Rgb;
+
+use crate::hittable::Hittable;
+use crate::material::MaterialKind;
+use crate::optics::{offset_point, random_unit_vector, reflect};
+use crate::scene::Scene;
+use crate::{sky_color, Ray};
+
+const MIN_DEPTH: usize = 4;
+
+/// Unidirectional Monte-Carlo path tracer: at each hit it returns
+/// `emission + albedo ⊗ incoming`, sampling a single bounce direction and
+/// relying on many samples per pixel (see `render`) to converge. Paths
+/// beyond `MIN_DEPTH` are terminated by Russian roulette; `max_depth` (the
+/// same scene-file setting the Whitted caster honors) is a hard cutoff.
+pub fn path_trace(ray: &Ray, scene: &Scene, depth: usize, max_depth: usize, rng: &mut impl Rng) -> Rgb<f64> {
+    if depth > max_depth {
+        return Rgb::black();
+    }
+
+    let Some(rec) = scene.hit(ray, 1e-3, f64::MAX) else {
+        return sky_color();
+    };
+    let (point, v_normal, material) = (rec.point, rec.normal, rec.material);
+
+    let albedo = material.diffuse_color;
+
+    let mut survival = 1.0;
+    if depth >= MIN_DEPTH {
+        survival = albedo.iter().cloned().fold(0.0_f64, f64::max).max(1e-3);
+        if rng.gen::<f64>() > survival {
+            return material.emission;
+        }
+    }
+
+    let scatter_dir = match material.kind {
+        MaterialKind::Diffuse => (v_normal + random_unit_vector(rng)).normalized(),
+        MaterialKind::Mirror => reflect(&ray.direction, &v_normal).normalized(),
+        MaterialKind::Glossy => {
+            let reflected = reflect(&ray.direction, &v_normal).normalized();
+            (reflected + random_unit_vector(rng) / material.specular_exponent.max(1.)).normalized()
+        }
+    };
+
+    let origin = offset_point(&point, &v_normal, scatter_dir.dot(v_normal));
+    let incoming = path_trace(&Ray::new(origin, scatter_dir, ray.time), scene, depth + 1, max_depth, rng);
+
+    material.emission + albedo * incoming / survival
+}
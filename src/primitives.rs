@@ -0,0 +1,275 @@
+use vek::{Rgb, Vec3};
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::Ray;
+
+/// Padding applied to otherwise zero-thickness bounding boxes (a flat plane,
+/// an axis-aligned triangle) so the BVH's slab test never divides a real
+/// interval down to nothing.
+const BOX_PADDING: f64 = 1e-4;
+
+#[derive(Clone, Copy)]
+pub struct Sphere {
+    pub center: Vec3<f64>,
+    pub radius: f64,
+    pub material: Material,
+}
+
+impl Sphere {
+    pub fn new(center: Vec3<f64>, radius: f64, material: Material) -> Self {
+        Self {
+            center,
+            radius,
+            material,
+        }
+    }
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let v_l = self.center - ray.origin;
+        let tca = v_l.dot(ray.direction);
+        let d2 = v_l.dot(v_l) - tca * tca;
+        let radius2 = self.radius * self.radius;
+
+        if d2 > radius2 {
+            return None;
+        }
+
+        let thc = (radius2 - d2).sqrt();
+        let mut t = tca - thc;
+        if t < t_min || t > t_max {
+            t = tca + thc;
+            if t < t_min || t > t_max {
+                return None;
+            }
+        }
+
+        let point = ray.origin + ray.direction * t;
+        let outward_normal = (point - self.center) / self.radius;
+        Some(HitRecord::new(ray, t, point, outward_normal, self.material))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let r = Vec3::new(self.radius, self.radius, self.radius);
+        Some(Aabb::new(self.center - r, self.center + r))
+    }
+}
+
+/// A sphere whose center travels linearly from `center0` at `time0` to
+/// `center1` at `time1`; rays outside that interval still hit it, clamped to
+/// whichever endpoint center is nearest in time.
+pub struct MovingSphere {
+    pub center0: Vec3<f64>,
+    pub center1: Vec3<f64>,
+    pub time0: f64,
+    pub time1: f64,
+    pub radius: f64,
+    pub material: Material,
+}
+
+impl MovingSphere {
+    pub fn new(
+        center0: Vec3<f64>,
+        center1: Vec3<f64>,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        material: Material,
+    ) -> Self {
+        Self {
+            center0,
+            center1,
+            time0,
+            time1,
+            radius,
+            material,
+        }
+    }
+
+    pub fn center(&self, time: f64) -> Vec3<f64> {
+        if self.time1 <= self.time0 {
+            return self.center0;
+        }
+        let fraction = ((time - self.time0) / (self.time1 - self.time0)).clamp(0., 1.);
+        self.center0 + fraction * (self.center1 - self.center0)
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let center = self.center(ray.time);
+        let v_l = center - ray.origin;
+        let tca = v_l.dot(ray.direction);
+        let d2 = v_l.dot(v_l) - tca * tca;
+        let radius2 = self.radius * self.radius;
+
+        if d2 > radius2 {
+            return None;
+        }
+
+        let thc = (radius2 - d2).sqrt();
+        let mut t = tca - thc;
+        if t < t_min || t > t_max {
+            t = tca + thc;
+            if t < t_min || t > t_max {
+                return None;
+            }
+        }
+
+        let point = ray.origin + ray.direction * t;
+        let outward_normal = (point - center) / self.radius;
+        Some(HitRecord::new(ray, t, point, outward_normal, self.material))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let r = Vec3::new(self.radius, self.radius, self.radius);
+        let box0 = Aabb::new(self.center0 - r, self.center0 + r);
+        let box1 = Aabb::new(self.center1 - r, self.center1 + r);
+        Some(Aabb::surrounding(&box0, &box1))
+    }
+}
+
+/// An axis-aligned checkerboard plane at a fixed height, bounded to a
+/// rectangular patch of the `xz` plane (matching the floor the original
+/// Whitted renderer special-cased inside `Scene::intersect`).
+pub struct Plane {
+    pub y: f64,
+    pub x_bounds: (f64, f64),
+    pub z_bounds: (f64, f64),
+    pub material: Material,
+    pub checker_color_a: Rgb<f64>,
+    pub checker_color_b: Rgb<f64>,
+}
+
+impl Plane {
+    pub fn new(
+        y: f64,
+        x_bounds: (f64, f64),
+        z_bounds: (f64, f64),
+        material: Material,
+        checker_color_a: Rgb<f64>,
+        checker_color_b: Rgb<f64>,
+    ) -> Self {
+        Self {
+            y,
+            x_bounds,
+            z_bounds,
+            material,
+            checker_color_a,
+            checker_color_b,
+        }
+    }
+}
+
+impl Hittable for Plane {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        if ray.direction.y.abs() <= 1e-3 {
+            return None;
+        }
+
+        let t = -(ray.origin.y - self.y) / ray.direction.y;
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let point = ray.origin + ray.direction * t;
+        if point.x < self.x_bounds.0
+            || point.x > self.x_bounds.1
+            || point.z < self.z_bounds.0
+            || point.z > self.z_bounds.1
+        {
+            return None;
+        }
+
+        let mut material = self.material;
+        material.diffuse_color = if ((0.5 * point.x + 1000.) as isize + (0.5 * point.z) as isize) & 1 == 1
+        {
+            self.checker_color_a
+        } else {
+            self.checker_color_b
+        };
+
+        let outward_normal = Vec3::new(0., 1., 0.);
+        Some(HitRecord::new(ray, t, point, outward_normal, material))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(Aabb::new(
+            Vec3::new(self.x_bounds.0, self.y - BOX_PADDING, self.z_bounds.0),
+            Vec3::new(self.x_bounds.1, self.y + BOX_PADDING, self.z_bounds.1),
+        ))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Triangle {
+    pub v0: Vec3<f64>,
+    pub v1: Vec3<f64>,
+    pub v2: Vec3<f64>,
+    pub material: Material,
+}
+
+impl Triangle {
+    pub fn new(v0: Vec3<f64>, v1: Vec3<f64>, v2: Vec3<f64>, material: Material) -> Self {
+        Self {
+            v0,
+            v1,
+            v2,
+            material,
+        }
+    }
+}
+
+impl Hittable for Triangle {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        const EPSILON: f64 = 1e-8;
+
+        let edge1 = self.v1 - self.v0;
+        let edge2 = self.v2 - self.v0;
+        let h = ray.direction.cross(edge2);
+        let a = edge1.dot(h);
+        if a.abs() < EPSILON {
+            return None;
+        }
+
+        let f = 1. / a;
+        let s = ray.origin - self.v0;
+        let u = f * s.dot(h);
+        if !(0. ..=1.).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = f * ray.direction.dot(q);
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+
+        let t = f * edge2.dot(q);
+        if t < t_min || t > t_max {
+            return None;
+        }
+
+        let point = ray.origin + ray.direction * t;
+        let outward_normal = edge1.cross(edge2).normalized();
+        Some(HitRecord::new(ray, t, point, outward_normal, self.material))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let pad = Vec3::new(BOX_PADDING, BOX_PADDING, BOX_PADDING);
+        let min = Vec3::new(
+            self.v0.x.min(self.v1.x).min(self.v2.x),
+            self.v0.y.min(self.v1.y).min(self.v2.y),
+            self.v0.z.min(self.v1.z).min(self.v2.z),
+        );
+        let max = Vec3::new(
+            self.v0.x.max(self.v1.x).max(self.v2.x),
+            self.v0.y.max(self.v1.y).max(self.v2.y),
+            self.v0.z.max(self.v1.z).max(self.v2.z),
+        );
+        Some(Aabb::new(min - pad, max + pad))
+    }
+}
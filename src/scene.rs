@@ -0,0 +1,29 @@
+use crate::aabb::Aabb;
+use crate::bvh;
+use crate::hittable::{HitRecord, Hittable};
+use crate::Ray;
+
+/// The full set of primitives in view, organized into a BVH so a ray only
+/// has to test the handful of objects near its path instead of every object
+/// in the scene.
+pub struct Scene {
+    bvh: Box<dyn Hittable>,
+}
+
+impl Scene {
+    pub fn new(objects: Vec<Box<dyn Hittable>>) -> Self {
+        Self {
+            bvh: bvh::build(objects),
+        }
+    }
+}
+
+impl Hittable for Scene {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        self.bvh.hit(ray, t_min, t_max)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.bvh.bounding_box()
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::{fs, io};
+
+use serde::Deserialize;
+use vek::{Rgb, Vec3};
+
+use crate::camera::Camera;
+use crate::hittable::Hittable;
+use crate::material::{Material, MaterialKind};
+use crate::obj;
+use crate::primitives::{MovingSphere, Plane, Sphere};
+use crate::scene::Scene;
+use crate::{Light, Renderer};
+
+#[derive(Deserialize)]
+struct Vec3Desc {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl From<Vec3Desc> for Vec3<f64> {
+    fn from(v: Vec3Desc) -> Self {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+#[derive(Deserialize)]
+struct RgbDesc {
+    r: f64,
+    g: f64,
+    b: f64,
+}
+
+impl From<RgbDesc> for Rgb<f64> {
+    fn from(c: RgbDesc) -> Self {
+        Rgb::new(c.r, c.g, c.b)
+    }
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum MaterialKindDesc {
+    #[default]
+    Diffuse,
+    Glossy,
+    Mirror,
+}
+
+impl From<MaterialKindDesc> for MaterialKind {
+    fn from(kind: MaterialKindDesc) -> Self {
+        match kind {
+            MaterialKindDesc::Diffuse => MaterialKind::Diffuse,
+            MaterialKindDesc::Glossy => MaterialKind::Glossy,
+            MaterialKindDesc::Mirror => MaterialKind::Mirror,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MaterialDesc {
+    name: String,
+    refractive_index: f64,
+    albedo: [f64; 4],
+    diffuse_color: RgbDesc,
+    specular_exponent: f64,
+    emission: Option<RgbDesc>,
+    #[serde(default)]
+    kind: MaterialKindDesc,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum PrimitiveDesc {
+    Sphere {
+        center: Vec3Desc,
+        radius: f64,
+        material: String,
+    },
+    MovingSphere {
+        center0: Vec3Desc,
+        center1: Vec3Desc,
+        radius: f64,
+        material: String,
+    },
+    Plane {
+        y: f64,
+        x_bounds: (f64, f64),
+        z_bounds: (f64, f64),
+        material: String,
+        checker_color_a: RgbDesc,
+        checker_color_b: RgbDesc,
+    },
+    ObjMesh {
+        path: String,
+        material: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct CameraDesc {
+    position: Vec3Desc,
+    look_at: Vec3Desc,
+    up: Vec3Desc,
+    fov: f64,
+    #[serde(default)]
+    aperture: f64,
+    #[serde(default = "default_focus_dist")]
+    focus_dist: f64,
+    #[serde(default)]
+    shutter_open: f64,
+    #[serde(default = "default_shutter_close")]
+    shutter_close: f64,
+}
+
+fn default_focus_dist() -> f64 {
+    1.
+}
+
+fn default_shutter_close() -> f64 {
+    1.
+}
+
+#[derive(Deserialize)]
+struct LightDesc {
+    position: Vec3Desc,
+    intensity: f64,
+}
+
+fn default_max_depth() -> usize {
+    4
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum RendererDesc {
+    #[default]
+    Whitted,
+    PathTracer,
+}
+
+impl From<RendererDesc> for Renderer {
+    fn from(renderer: RendererDesc) -> Self {
+        match renderer {
+            RendererDesc::Whitted => Renderer::Whitted,
+            RendererDesc::PathTracer => Renderer::PathTracer,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SceneDesc {
+    width: usize,
+    height: usize,
+    #[serde(default = "default_max_depth")]
+    max_depth: usize,
+    #[serde(default)]
+    renderer: RendererDesc,
+    camera: CameraDesc,
+    materials: Vec<MaterialDesc>,
+    primitives: Vec<PrimitiveDesc>,
+    lights: Vec<LightDesc>,
+}
+
+/// Everything `main` needs to render a scene, parsed out of a JSON file.
+pub struct LoadedScene {
+    pub width: usize,
+    pub height: usize,
+    pub max_depth: usize,
+    pub renderer: Renderer,
+    pub camera: Camera,
+    pub scene: Scene,
+    pub lights: Vec<Light>,
+}
+
+/// Reads and parses a JSON scene description from `path`, resolving material
+/// names on primitives against the file's own `materials` list, and building
+/// the `Camera`/`Scene`/lights the renderer drives off of.
+pub fn load(path: &str) -> io::Result<LoadedScene> {
+    let contents = fs::read_to_string(path)?;
+    let desc: SceneDesc =
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let materials: HashMap<String, Material> = desc
+        .materials
+        .into_iter()
+        .map(|m| {
+            let mut material =
+                Material::new(m.refractive_index, m.albedo, m.diffuse_color.into(), m.specular_exponent)
+                    .with_kind(m.kind.into());
+            if let Some(emission) = m.emission {
+                material = material.with_emission(emission.into());
+            }
+            (m.name, material)
+        })
+        .collect();
+
+    let material_for = |name: &str| -> io::Result<Material> {
+        materials
+            .get(name)
+            .copied()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("unknown material {name:?}")))
+    };
+
+    let mut objects: Vec<Box<dyn Hittable>> = Vec::new();
+    for primitive in desc.primitives {
+        match primitive {
+            PrimitiveDesc::Sphere { center, radius, material } => {
+                objects.push(Box::new(Sphere::new(center.into(), radius, material_for(&material)?)));
+            }
+            PrimitiveDesc::MovingSphere { center0, center1, radius, material } => {
+                objects.push(Box::new(MovingSphere::new(
+                    center0.into(),
+                    center1.into(),
+                    desc.camera.shutter_open,
+                    desc.camera.shutter_close,
+                    radius,
+                    material_for(&material)?,
+                )));
+            }
+            PrimitiveDesc::Plane {
+                y,
+                x_bounds,
+                z_bounds,
+                material,
+                checker_color_a,
+                checker_color_b,
+            } => {
+                objects.push(Box::new(Plane::new(
+                    y,
+                    x_bounds,
+                    z_bounds,
+                    material_for(&material)?,
+                    checker_color_a.into(),
+                    checker_color_b.into(),
+                )));
+            }
+            PrimitiveDesc::ObjMesh { path, material } => {
+                let mesh = obj::load_obj(&path, material_for(&material)?)?;
+                objects.extend(mesh.into_iter().map(|t| Box::new(t) as Box<dyn Hittable>));
+            }
+        }
+    }
+
+    if objects.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "scene has no primitives; the BVH needs at least one",
+        ));
+    }
+
+    let lights = desc
+        .lights
+        .into_iter()
+        .map(|l| Light::new(l.position.into(), l.intensity))
+        .collect();
+
+    let camera = Camera::new(
+        desc.camera.position.into(),
+        desc.camera.look_at.into(),
+        desc.camera.up.into(),
+        desc.camera.fov,
+        desc.width as f64 / desc.height as f64,
+        desc.camera.aperture,
+        desc.camera.focus_dist,
+        desc.camera.shutter_open,
+        desc.camera.shutter_close,
+    );
+
+    Ok(LoadedScene {
+        width: desc.width,
+        height: desc.height,
+        max_depth: desc.max_depth,
+        renderer: desc.renderer.into(),
+        camera,
+        scene: Scene::new(objects),
+        lights,
+    })
+}